@@ -50,13 +50,13 @@ fn run_jaq_transform(input: Bytes, filter: &Filter<Native<Val>>) -> Result<Optio
                 Ok(None)
             } else {
                 let buf = serde_json::to_vec(&out_json[0])?;
-                Ok(Some(buf.into()))
+                Ok(Some(buf))
             }
         }
         _ => {
             let result: Vec<Value> = out_json.into_iter().filter(|v| !v.is_null()).collect();
             let buf = serde_json::to_vec(&result)?;
-            Ok(Some(buf.into()))
+            Ok(Some(buf))
         }
     }
 }
@@ -131,7 +131,7 @@ mod test {
         .as_bytes()
         .to_vec();
         let filter = ".[] | .name";
-        let filter = create_filter(&filter).expect("cannot create filter");
+        let filter = create_filter(filter).expect("cannot create filter");
 
         let raw_result = run_jaq_transform(creatures, &filter)
             .expect("cannot transform")
@@ -148,7 +148,7 @@ mod test {
         let input_file: Vec<u8> = std::fs::read("../../sample-data/fish.json")
             .expect("cannot read fish - input file");
         let filter = ".[] | .name";
-        let filter = create_filter(&filter).expect("cannot create filter");
+        let filter = create_filter(filter).expect("cannot create filter");
         let output_file: Vec<u8> = std::fs::read("../../sample-data/output/fish.json")
             .expect("cannot read fish - output file");
 
@@ -388,7 +388,7 @@ fn format_header(event: &str, emoji: Option<&str>, livemode: bool) -> String {
     if let Some(emoji) = emoji {
         header.push_str(&format!("{} ", emoji));
     }
-    header.push_str(&format!("{}", event));
+    header.push_str(event);
     if livemode {
         header.push_str(" - :white_check_mark:");
     } else {
@@ -525,6 +525,17 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_format_money() {
+        // Stripe amounts are integer minor units (cents). Pin the exact
+        // decimal output so a future switch to a floating amount type
+        // (losing cents on large invoices) shows up here first.
+        assert_eq!(format_money(0, "USD"), "0.00 USD");
+        assert_eq!(format_money(1, "USD"), "0.01 USD");
+        assert_eq!(format_money(292003, "USD"), "2920.03 USD");
+        assert_eq!(format_money(100_000_007, "USD"), "1000000.07 USD");
+    }
+
     #[test]
     fn test_format_timestamp() {
         let ts = 1633036800;